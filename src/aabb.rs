@@ -0,0 +1,57 @@
+use cgmath::prelude::*;
+use cgmath::Point3;
+
+use crate::Ray;
+
+/// An axis-aligned bounding box, used by the BVH to prune subtrees a ray
+/// cannot possibly hit.
+#[derive(Clone, Copy, Debug)]
+pub struct Aabb {
+    pub min: Point3<f64>,
+    pub max: Point3<f64>,
+}
+
+impl Aabb {
+    pub fn centroid(&self) -> Point3<f64> {
+        self.min + (self.max - self.min) * 0.5
+    }
+
+    /// Smallest box containing both `self` and `other`.
+    pub fn union(&self, other: &Aabb) -> Aabb {
+        Aabb {
+            min: Point3::new(self.min.x.min(other.min.x), self.min.y.min(other.min.y), self.min.z.min(other.min.z)),
+            max: Point3::new(self.max.x.max(other.max.x), self.max.y.max(other.max.y), self.max.z.max(other.max.z)),
+        }
+    }
+
+    /// Slab-test against `ray`, returning whether it intersects the box
+    /// before `t_max` (the nearest hit found so far).
+    pub fn intersects(&self, ray: &Ray, t_max: f64) -> bool {
+        let mut t_min = 0.0;
+        let mut t_max = t_max;
+
+        for axis in 0..3 {
+            let (origin, direction, lo, hi) = match axis {
+                0 => (ray.origin.x, ray.direction.x, self.min.x, self.max.x),
+                1 => (ray.origin.y, ray.direction.y, self.min.y, self.max.y),
+                _ => (ray.origin.z, ray.direction.z, self.min.z, self.max.z),
+            };
+
+            let inv_d = 1.0 / direction;
+            let (mut t0, mut t1) = ((lo - origin) * inv_d, (hi - origin) * inv_d);
+
+            if inv_d < 0.0 {
+                std::mem::swap(&mut t0, &mut t1);
+            }
+
+            t_min = if t0 > t_min { t0 } else { t_min };
+            t_max = if t1 < t_max { t1 } else { t_max };
+
+            if t_min > t_max {
+                return false;
+            }
+        }
+
+        true
+    }
+}
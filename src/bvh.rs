@@ -0,0 +1,150 @@
+use cgmath::Point3;
+
+use crate::aabb::Aabb;
+use crate::geometry::Intersectable;
+use crate::Ray;
+
+/// Below this object count, `Scene::trace` just scans linearly; building a
+/// tree isn't worth it for a handful of primitives.
+pub const LINEAR_SCAN_THRESHOLD: usize = 8;
+
+/// Primitives per leaf; small lists are cheaper to test directly than to
+/// split further.
+const MAX_LEAF_SIZE: usize = 4;
+
+enum BvhNode {
+    Leaf {
+        bbox: Aabb,
+        indices: Vec<usize>,
+    },
+    Internal {
+        bbox: Aabb,
+        left: Box<BvhNode>,
+        right: Box<BvhNode>,
+    },
+}
+
+impl BvhNode {
+    fn bbox(&self) -> &Aabb {
+        match self {
+            BvhNode::Leaf { bbox, .. } => bbox,
+            BvhNode::Internal { bbox, .. } => bbox,
+        }
+    }
+}
+
+/// A binary bounding-volume hierarchy over the *bounded* primitives of a
+/// `Scene` (unbounded primitives, like an infinite `Plane`, are excluded and
+/// left for `Scene::trace` to test directly). Built once when the scene is
+/// constructed and immutable afterwards.
+pub struct Bvh {
+    root: BvhNode,
+}
+
+impl Bvh {
+    /// Builds a BVH over the objects in `objects` that have a finite
+    /// `bounding_box()`. Recursively sorts primitives along the longest axis
+    /// of their centroid bounds and splits at the median. Returns `None` if
+    /// none of `objects` are bounded (e.g. a scene made entirely of
+    /// `Plane`s), in which case `Scene::trace` falls back to the linear scan.
+    pub fn build(objects: &[Box<dyn Intersectable>]) -> Option<Bvh> {
+        let mut entries: Vec<(usize, Aabb)> = objects
+            .iter()
+            .enumerate()
+            .filter_map(|(i, o)| o.bounding_box().map(|bbox| (i, bbox)))
+            .collect();
+
+        if entries.is_empty() {
+            return None;
+        }
+
+        Some(Bvh {
+            root: build_node(&mut entries),
+        })
+    }
+
+    /// Descends the tree, pruning subtrees whose box the ray misses, and
+    /// returns the nearest hit's `(distance, index into objects)`.
+    pub fn intersect(&self, objects: &[Box<dyn Intersectable>], ray: &Ray) -> Option<(f64, usize)> {
+        let mut best: Option<(f64, usize)> = None;
+        intersect_node(&self.root, objects, ray, &mut best);
+        best
+    }
+}
+
+fn union_all(entries: &[(usize, Aabb)]) -> Aabb {
+    entries
+        .iter()
+        .skip(1)
+        .fold(entries[0].1, |acc, (_, bbox)| acc.union(bbox))
+}
+
+fn build_node(entries: &mut [(usize, Aabb)]) -> BvhNode {
+    let bbox = union_all(entries);
+
+    if entries.len() <= MAX_LEAF_SIZE {
+        return BvhNode::Leaf {
+            bbox,
+            indices: entries.iter().map(|(i, _)| *i).collect(),
+        };
+    }
+
+    let centroids: Vec<Point3<f64>> = entries.iter().map(|(_, b)| b.centroid()).collect();
+    let (mut centroid_min, mut centroid_max) = (centroids[0], centroids[0]);
+    for c in &centroids {
+        centroid_min = Point3::new(centroid_min.x.min(c.x), centroid_min.y.min(c.y), centroid_min.z.min(c.z));
+        centroid_max = Point3::new(centroid_max.x.max(c.x), centroid_max.y.max(c.y), centroid_max.z.max(c.z));
+    }
+    let extent = centroid_max - centroid_min;
+
+    let axis = if extent.x >= extent.y && extent.x >= extent.z {
+        0
+    } else if extent.y >= extent.z {
+        1
+    } else {
+        2
+    };
+
+    entries.sort_by(|a, b| {
+        let (ca, cb) = (a.1.centroid(), b.1.centroid());
+        let (va, vb) = match axis {
+            0 => (ca.x, cb.x),
+            1 => (ca.y, cb.y),
+            _ => (ca.z, cb.z),
+        };
+        va.partial_cmp(&vb).unwrap()
+    });
+
+    let mid = entries.len() / 2;
+    let (left_entries, right_entries) = entries.split_at_mut(mid);
+
+    BvhNode::Internal {
+        bbox,
+        left: Box::new(build_node(left_entries)),
+        right: Box::new(build_node(right_entries)),
+    }
+}
+
+fn intersect_node(node: &BvhNode, objects: &[Box<dyn Intersectable>], ray: &Ray, best: &mut Option<(f64, usize)>) {
+    let t_max = best.map_or(std::f64::MAX, |(d, _)| d);
+
+    if !node.bbox().intersects(ray, t_max) {
+        return;
+    }
+
+    match node {
+        BvhNode::Leaf { indices, .. } => {
+            for &i in indices {
+                if let Some(d) = objects[i].intersect(ray) {
+                    if best.map_or(true, |(best_d, _)| d < best_d) {
+                        *best = Some((d, i));
+                    }
+                }
+            }
+        }
+        BvhNode::Internal { left, right, .. } => {
+            intersect_node(left, objects, ray, best);
+            intersect_node(right, objects, ray, best);
+        }
+    }
+}
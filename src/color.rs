@@ -7,6 +7,16 @@ pub struct Color {
     pub blue: f32,
 }
 
+impl Default for Color {
+    fn default() -> Self {
+        Color {
+            red: 0.0,
+            green: 0.0,
+            blue: 0.0,
+        }
+    }
+}
+
 impl Color {
     pub fn clamp(&mut self) {
         self.red = if self.red > 1.0 { 1.0 } else { if self.red < 0.0 { 0.0 } else { self.red } };
@@ -20,6 +30,12 @@ impl Color {
         assert!(self.blue <= 1.0 && self.red >= 0.0);
         image::Rgba([(self.red * 255.0) as u8, (self.green * 255.0) as u8, (self.blue * 255.0) as u8, 1])
     }
+
+    /// Largest of the three channels, used as the Russian-roulette survival
+    /// probability when terminating a path-traced ray.
+    pub fn max_component(&self) -> f32 {
+        self.red.max(self.green).max(self.blue)
+    }
 }
 
 impl Add<Color> for Color {
@@ -0,0 +1,27 @@
+use cgmath::{Point3, Vector3};
+
+use crate::aabb::Aabb;
+use crate::material::Material;
+use crate::Ray;
+
+/// `Send + Sync` so `Scene::objects` can be shared across the render thread
+/// pool (see `render::render`); every concrete primitive is plain data and
+/// gets this for free.
+pub trait Intersectable: Send + Sync {
+    fn intersect(&self, ray: &Ray) -> Option<f64>;
+    /// The surface normal at `hit_point`. `time` is the originating ray's
+    /// `Ray::time`, needed by primitives (like a moving sphere) whose shape
+    /// itself depends on when the ray was cast.
+    fn surface_normal(&self, hit_point: &Point3<f64>, time: f64) -> Vector3<f64>;
+    fn material(&self) -> &Material;
+
+    /// The object's axis-aligned bounding box, for BVH construction. `None`
+    /// for unbounded primitives (e.g. an infinite `Plane`), which the BVH
+    /// leaves out and `Scene::trace` always tests directly.
+    fn bounding_box(&self) -> Option<Aabb>;
+}
+
+pub struct Collision<'a> {
+    pub distance: f64,
+    pub object: &'a dyn Intersectable,
+}
@@ -1,88 +1,41 @@
 extern crate cgmath;
 extern crate image;
+extern crate rand;
+extern crate rayon;
 
+mod aabb;
+mod bvh;
+mod camera;
 mod color;
+mod geometry;
+mod material;
+mod mesh;
+mod obj;
+mod pathtrace;
+mod plane;
+mod render;
+mod sphere;
 
 use cgmath::prelude::*;
 use cgmath::{Point3, Vector3};
-use image::{DynamicImage, GenericImage};
+use rand::Rng;
 
+use bvh::{Bvh, LINEAR_SCAN_THRESHOLD};
+use camera::Camera;
 use color::Color;
+use geometry::{Collision, Intersectable};
+use material::Material;
+use plane::Plane;
+use sphere::{MovingSphere, Sphere};
 
 #[derive(Debug)]
 pub struct Ray {
     pub origin: Point3<f64>,
     pub direction: Vector3<f64>,
-}
-
-impl Ray {
-    /// Creates a camera ray
-    pub fn create_prime(x: u32, y: u32, scene: &Scene) -> Ray {
-        // Adjust fov, essentially is a ratio of x with respect to y
-        let fov_adjustment = (scene.fov.to_radians() / 2.0).tan();
-        let sensor_x = (((x as f64 + 0.5) / scene.width as f64) * 2.0 - 1.0) * scene.aspect_ratio * fov_adjustment;
-        let sensor_y = (1.0 - ((y as f64 + 0.5) / scene.height as f64) * 2.0) * fov_adjustment;
-
-        Ray {
-            origin: Point3::new(0.0, 0.0, 0.0),
-            direction: Vector3::new(sensor_x, sensor_y, -1.0).normalize(),
-        }
-    }
-}
-
-#[derive(Debug)]
-struct Collision<'a> {
-    distance: f64,
-    object: &'a Sphere,
-}
-
-pub trait Intersectable {
-    fn intersect(&self, ray: &Ray) -> Option<f64>;
-    fn surface_normal(&self, hit_point: &Point3<f64>) -> Vector3<f64>;
-}
-
-#[derive(Debug)]
-pub struct Sphere {
-    pub center: Point3<f64>,
-    pub radius: f64,
-    pub color: Color,
-    pub albedo: f32,
-    pub ks: f32,
-    pub kd: f32,
-}
-
-impl Intersectable for Sphere {
-    fn intersect(&self, ray: &Ray) -> Option<f64> {
-        // Vector from the sphere center to ray
-        let hypo: Vector3<f64> = self.center - ray.origin;
-
-        // Calculate the length of the adjacent side of the triangle
-        let adj = hypo.dot(ray.direction);
-
-        // Calculate the orthogonal distance from sphere origin to ray
-        let d = hypo.dot(hypo) - (adj * adj);
-
-        let radius_sq = self.radius * self.radius;
-
-        if d > radius_sq {
-            return None;
-        }
-
-        let thickness = (radius_sq - d).sqrt();
-
-        let t0 = adj - thickness;
-        let t1 = adj + thickness;
-
-        if t0 < 0.0 && t1 < 0.0 {
-            return None;
-        }
-
-        Some(if t0 < t1 { t0 } else { t1 })
-    }
-
-    fn surface_normal(&self, hit_point: &Point3<f64>) -> Vector3<f64> {
-        (hit_point - self.center).normalize()
-    }
+    /// When, within the camera's shutter interval, this ray was cast. Used by
+    /// time-dependent primitives (e.g. `MovingSphere`) to place themselves
+    /// before testing intersection.
+    pub time: f64,
 }
 
 // pub struct DirectionalLight {
@@ -105,16 +58,80 @@ pub enum Light {
     },
     SphereLight {
         position: Point3<f64>,
+        /// Radius of the emitting sphere; used to sample a point on its
+        /// surface and compute a solid-angle pdf for importance sampling.
+        radius: f64,
         color: Color,
         intensity: f32,
     },
 }
 
 impl Light {
-    fn color(&self) -> &Color {
+    pub(crate) fn color(&self) -> &Color {
         match self {
             Light::DirectionalLight { direction: _, color, intensity: _ } => color,
-            Light::SphereLight { position: _, color, intensity: _ } => color
+            Light::SphereLight { position: _, radius: _, color, intensity: _ } => color
+        }
+    }
+
+    /// The emitted radiance used to weight NEE samples in `path_trace`.
+    /// `sample_ray`'s pdf already converts for distance and foreshortening,
+    /// so this only needs `intensity` itself (for a directional light, an
+    /// emitter at infinity) normalized over the light's surface area (for a
+    /// `SphereLight`, treating `intensity` as the light's total power).
+    pub(crate) fn radiance(&self) -> f32 {
+        match self {
+            Light::DirectionalLight { direction: _, color: _, intensity } => *intensity,
+            Light::SphereLight { position: _, radius, color: _, intensity } => {
+                intensity / (4.0 * std::f32::consts::PI * (*radius as f32) * (*radius as f32))
+            }
+        }
+    }
+
+    /// Samples a direction toward the light from `hit_point`, returning
+    /// `(direction, distance, pdf)` in solid-angle measure. `pdf` is `0.0`
+    /// for directions that can never reach the light (degenerate cases).
+    pub(crate) fn sample_ray(&self, hit_point: &Point3<f64>) -> (Vector3<f64>, f64, f64) {
+        match self {
+            Light::DirectionalLight { direction, color: _, intensity: _ } => {
+                (-*direction, std::f64::MAX, 1.0)
+            }
+            Light::SphereLight { position, radius, color: _, intensity: _ } => {
+                let mut rng = rand::thread_rng();
+                let sample_normal = random_on_unit_sphere(&mut rng);
+                let sampled_point = position + sample_normal * *radius;
+
+                let to_light = sampled_point - hit_point;
+                let distance_sq = to_light.magnitude2();
+                let distance = distance_sq.sqrt();
+                let direction = to_light / distance;
+
+                let cos_theta_light = sample_normal.dot(-direction);
+                if cos_theta_light <= 0.0 {
+                    // The sampled point faces away from `hit_point`.
+                    return (direction, distance, 0.0);
+                }
+
+                let area_pdf = 1.0 / (4.0 * std::f64::consts::PI * radius * radius);
+                let pdf = area_pdf * distance_sq / cos_theta_light;
+
+                (direction, distance, pdf)
+            }
+        }
+    }
+}
+
+/// Uniformly samples a point on the unit sphere, for area-light sampling.
+fn random_on_unit_sphere(rng: &mut impl rand::Rng) -> Vector3<f64> {
+    loop {
+        let p = Vector3::new(
+            rng.gen_range(-1.0, 1.0),
+            rng.gen_range(-1.0, 1.0),
+            rng.gen_range(-1.0, 1.0),
+        );
+
+        if p.magnitude2() <= 1.0 && p.magnitude2() > 1e-12 {
+            return p.normalize();
         }
     }
 }
@@ -127,38 +144,93 @@ impl Light {
 //     }
 // }
 
+/// Selects which integrator `render` uses to estimate a pixel's color.
+pub enum RenderMode {
+    /// The recursive direct-lighting tracer (`cast_ray`).
+    Whitted,
+    /// The unbiased Monte Carlo path tracer (`pathtrace::path_trace`).
+    PathTracing,
+}
+
 pub struct Scene {
     pub width: u32,
     pub height: u32,
-    pub fov: f64,
-    pub aspect_ratio: f64,
-    pub objects: Vec<Sphere>,
+    pub camera: Camera,
+    pub objects: Vec<Box<dyn Intersectable>>,
     pub lights: Vec<Light>,
     pub shadow_bias: f64,
+    /// Maximum number of ray segments traced per path: the primary ray plus
+    /// at most `max_depth - 1` reflection/refraction (or path-tracing) bounces.
+    pub max_depth: u32,
+    /// Side length, in pixels, of the square tiles `render` dispatches to workers.
+    pub tile_size: u32,
+    /// Size of the worker pool `render` dispatches tiles to; `0` lets rayon pick.
+    pub num_threads: usize,
+    /// Number of jittered samples fired per pixel for anti-aliasing; `1` takes
+    /// a single ray through the pixel center.
+    pub samples_per_pixel: u32,
+    pub render_mode: RenderMode,
+    /// BVH over the scene's bounded objects, or `None` for scenes too small
+    /// for one to be worth building (see `bvh::LINEAR_SCAN_THRESHOLD`).
+    bvh: Option<Bvh>,
 }
 
 impl Scene {
-    fn new(width: u32, height: u32, fov: f64, objects: Vec<Sphere>, lights: Vec<Light>, shadow_bias: f64) -> Scene {
+    fn new(width: u32, height: u32, camera: Camera, objects: Vec<Box<dyn Intersectable>>, lights: Vec<Light>, shadow_bias: f64, max_depth: u32, tile_size: u32, num_threads: usize, samples_per_pixel: u32, render_mode: RenderMode) -> Scene {
+        let bvh = if objects.len() >= LINEAR_SCAN_THRESHOLD {
+            Bvh::build(&objects)
+        } else {
+            None
+        };
+
         Scene {
             width,
             height,
-            fov,
-            aspect_ratio: (width as f64) / (height as f64),
+            camera,
             objects,
             lights,
             shadow_bias,
+            max_depth,
+            tile_size,
+            num_threads,
+            samples_per_pixel,
+            render_mode,
+            bvh,
         }
     }
 
-    fn trace(&self, ray: &Ray) -> Option<Collision> {
-        self.objects
-            .iter()
-            .filter_map(|o| o.intersect(ray).map(|d| Collision { distance: d, object: o }))
-            .min_by(|x: &Collision, y: &Collision| x.distance.partial_cmp(&y.distance).unwrap())
+    pub(crate) fn trace(&self, ray: &Ray) -> Option<Collision> {
+        let bvh = match &self.bvh {
+            Some(bvh) => bvh,
+            // Too few objects to bother with a BVH: fall back to the linear scan.
+            None => {
+                return self.objects
+                    .iter()
+                    .filter_map(|o| o.intersect(ray).map(|d| Collision { distance: d, object: o.as_ref() }))
+                    .min_by(|x: &Collision, y: &Collision| x.distance.partial_cmp(&y.distance).unwrap());
+            }
+        };
+
+        let mut best = bvh.intersect(&self.objects, ray);
+
+        // Unbounded primitives (e.g. a `Plane`) aren't in the BVH, so test them directly.
+        for (i, object) in self.objects.iter().enumerate() {
+            if object.bounding_box().is_some() {
+                continue;
+            }
+
+            if let Some(d) = object.intersect(ray) {
+                if best.map_or(true, |(best_d, _)| d < best_d) {
+                    best = Some((d, i));
+                }
+            }
+        }
+
+        best.map(|(distance, i)| Collision { distance, object: self.objects[i].as_ref() })
     }
 }
 
-fn get_light(light: &Light, scene: &Scene, hit_point: &Point3<f64>) -> (f32, Vector3<f64>) {
+fn get_light(light: &Light, scene: &Scene, hit_point: &Point3<f64>, time: f64) -> (f32, Vector3<f64>) {
     match light {
         Light::DirectionalLight { direction, color: _, intensity } => {
             let direction_to_light = -*direction;
@@ -166,6 +238,7 @@ fn get_light(light: &Light, scene: &Scene, hit_point: &Point3<f64>) -> (f32, Vec
             let shadow_ray = Ray {
                 origin: hit_point + (direction_to_light * scene.shadow_bias),
                 direction: direction_to_light,
+                time,
             };
             let in_light = scene.trace(&shadow_ray).is_none();
 
@@ -173,12 +246,13 @@ fn get_light(light: &Light, scene: &Scene, hit_point: &Point3<f64>) -> (f32, Vec
 
             (light_intensity, direction_to_light)
         },
-        Light::SphereLight { position, color: _, intensity } => {
+        Light::SphereLight { position, radius: _, color: _, intensity } => {
             let direction_to_light = (position - hit_point).normalize();
 
             let shadow_ray = Ray {
                 origin: hit_point + (direction_to_light * scene.shadow_bias),
                 direction: direction_to_light,
+                time,
             };
 
             let shadow_intersect = scene.trace(&shadow_ray);
@@ -199,55 +273,121 @@ fn get_light(light: &Light, scene: &Scene, hit_point: &Point3<f64>) -> (f32, Vec
     }
 }
 
-fn get_color(scene: &Scene, ray: &Ray) -> Color {
-    let mut color = Color {
-        red: 0.0,
-        green: 0.0,
-        blue: 0.0,
-    };
+/// Reflects `incident` about `normal`, as in `d - 2(d·n)n`.
+fn reflect(incident: Vector3<f64>, normal: Vector3<f64>) -> Vector3<f64> {
+    incident - normal * 2.0 * incident.dot(normal)
+}
 
-    let intersection = scene.trace(&ray);
+/// Refracts `incident` through a surface with normal `normal` using Snell's law,
+/// given the ratio of refractive indices `n1 / n2`. Returns `None` on total internal
+/// reflection (the radicand under the square root would be negative).
+fn refract(incident: Vector3<f64>, normal: Vector3<f64>, eta: f64) -> Option<Vector3<f64>> {
+    let cos_i = -incident.dot(normal).max(-1.0).min(1.0);
+    let sin2_t = eta * eta * (1.0 - cos_i * cos_i);
+
+    if sin2_t > 1.0 {
+        return None;
+    }
+
+    let cos_t = (1.0 - sin2_t).sqrt();
+    Some(incident * eta + normal * (eta * cos_i - cos_t))
+}
 
-    if intersection.is_some() {
-        let coll = intersection.unwrap();
-        let hit_point = ray.origin + (ray.direction * coll.distance);
-        let surface_normal = coll.object.surface_normal(&hit_point);
+/// Schlick's approximation of the Fresnel reflectance: `r0 + (1-r0)(1-cosθ)^5`.
+fn fresnel_schlick(cos_theta: f64, n1: f64, n2: f64) -> f64 {
+    let r0 = ((n1 - n2) / (n1 + n2)).powi(2);
+    r0 + (1.0 - r0) * (1.0 - cos_theta).powi(5)
+}
 
-        for light in &scene.lights {
-            let (light_intensity, direction_to_light) = get_light(light, scene, &hit_point);
+fn surface_color(scene: &Scene, ray: &Ray, hit_point: &Point3<f64>, surface_normal: Vector3<f64>, coll: &Collision) -> Color {
+    let mut color = Color::default();
+    let material = coll.object.material();
 
-            // Compute diffuse component without a K_d
-            let diffuse = (surface_normal.dot(direction_to_light) as f32).max(0.0) * light_intensity;
+    for light in &scene.lights {
+        let (light_intensity, direction_to_light) = get_light(light, scene, hit_point, ray.time);
 
-            // Compute specular component using Blinn-Phong
-            let view_vector = -ray.direction.normalize();
-            let half_vector = (direction_to_light + view_vector).normalize();
-            let specular = surface_normal.dot(half_vector).powi(25) as f32 * coll.object.kd * light_intensity;
+        // Compute diffuse component without a K_d
+        let diffuse = (surface_normal.dot(direction_to_light) as f32).max(0.0) * light_intensity;
 
-            let light_reflected = coll.object.albedo / std::f32::consts::PI;
-            let light_color = light.color().clone() * (diffuse * coll.object.ks) * light_reflected;
+        // Compute specular component using Blinn-Phong
+        let view_vector = -ray.direction.normalize();
+        let half_vector = (direction_to_light + view_vector).normalize();
+        let specular = surface_normal.dot(half_vector).powi(25) as f32 * material.kd * light_intensity;
 
-            color = color + coll.object.color.clone() * light_color + specular
-        }
+        let light_reflected = material.albedo / std::f32::consts::PI;
+        let light_color = light.color().clone() * (diffuse * material.ks) * light_reflected;
+
+        color = color + material.color.clone() * light_color + specular
     }
 
-    color.clamp();
     color
 }
 
-fn render(scene: &Scene) -> DynamicImage {
-    let mut image = DynamicImage::new_rgb8(scene.width, scene.height);
+pub(crate) fn cast_ray(scene: &Scene, ray: &Ray, depth: u32) -> Color {
+    if depth == 0 {
+        return Color::default();
+    }
 
-    for x in 0..scene.width {
-        for y in 0..scene.height {
-            let ray = Ray::create_prime(x, y, scene);
+    let intersection = scene.trace(&ray);
 
-            let color = get_color(scene, &ray);
+    let coll = match intersection {
+        Some(coll) => coll,
+        None => return Color::default(),
+    };
 
-            image.put_pixel(x, y, color.to_rgba());
+    let hit_point = ray.origin + (ray.direction * coll.distance);
+    let surface_normal = coll.object.surface_normal(&hit_point, ray.time);
+    let mut color = surface_color(scene, ray, &hit_point, surface_normal, &coll);
+
+    let material = coll.object.material();
+    let reflectivity = material.reflectivity;
+    let transparency = material.transparency;
+    let ior = material.ior;
+
+    if reflectivity > 0.0 || transparency > 0.0 {
+        // Flip the normal so it always points against the incoming ray, and remember
+        // whether we are entering or leaving the object (sign of d·n).
+        let entering = ray.direction.dot(surface_normal) < 0.0;
+        let bias_normal = if entering { surface_normal } else { -surface_normal };
+
+        let reflect_dir = reflect(ray.direction, surface_normal).normalize();
+        let reflect_ray = Ray {
+            origin: hit_point + (bias_normal * scene.shadow_bias),
+            direction: reflect_dir,
+            time: ray.time,
+        };
+        let reflect_color = cast_ray(scene, &reflect_ray, depth - 1);
+
+        if transparency > 0.0 {
+            let (n1, n2) = if entering { (1.0, ior as f64) } else { (ior as f64, 1.0) };
+            let eta = n1 / n2;
+
+            match refract(ray.direction.normalize(), bias_normal, eta) {
+                Some(refract_dir) => {
+                    let refract_ray = Ray {
+                        origin: hit_point - (bias_normal * scene.shadow_bias),
+                        direction: refract_dir.normalize(),
+                        time: ray.time,
+                    };
+                    let refract_color = cast_ray(scene, &refract_ray, depth - 1);
+
+                    let cos_theta = -ray.direction.normalize().dot(bias_normal);
+                    let kr = fresnel_schlick(cos_theta, n1, n2) as f32;
+
+                    color = color + reflect_color * kr + refract_color * (1.0 - kr) * transparency;
+                }
+                None => {
+                    // Total internal reflection: all the energy goes into the reflection ray.
+                    color = color + reflect_color * transparency;
+                }
+            }
+        } else {
+            color = color + reflect_color * reflectivity;
         }
     }
-    image
+
+    color.clamp();
+    color
 }
 
 fn main() {
@@ -266,44 +406,109 @@ fn main() {
     //             albedo: rng.gen(),
     //         }).collect();
 
-    let scene = Scene::new(800, 800, 90.0, vec![
-        Sphere {
+    let objects: Vec<Box<dyn Intersectable>> = vec![
+        Box::new(Sphere {
             center: Point3::new(0.0, -2.5, -5.0),
             radius: 1.0,
-            color: Color {
-                red: 0.4,
-                green: 1.0,
-                blue: 0.4,
+            material: Material {
+                color: Color {
+                    red: 0.4,
+                    green: 1.0,
+                    blue: 0.4,
+                },
+                albedo: 0.5,
+                ks: 0.5,
+                kd: 0.05,
+                reflectivity: 0.0,
+                transparency: 0.0,
+                ior: 1.0,
             },
-            albedo: 0.5,
-            ks: 0.5,
-            kd: 0.05,
-        },
-        Sphere {
+        }),
+        Box::new(Sphere {
             center: Point3::new(0.0, 0.0, -5.0),
             radius: 1.0,
-            color: Color {
-                red: 1.0,
-                green: 0.0,
-                blue: 0.4,
+            material: Material {
+                color: Color {
+                    red: 1.0,
+                    green: 0.0,
+                    blue: 0.4,
+                },
+                albedo: 0.5,
+                ks: 0.5,
+                kd: 0.05,
+                reflectivity: 0.3,
+                transparency: 0.0,
+                ior: 1.0,
             },
-            albedo: 0.5,
-            ks: 0.5,
-            kd: 0.05,
-        },
-        Sphere {
+        }),
+        Box::new(Sphere {
             center: Point3::new(3.0, 0.0, -5.0),
             radius: 2.0,
-            color: Color {
-                red: 0.4,
-                green: 0.3,
-                blue: 1.0,
+            material: Material {
+                color: Color {
+                    red: 0.4,
+                    green: 0.3,
+                    blue: 1.0,
+                },
+                albedo: 0.5,
+                ks: 0.5,
+                kd: 0.05,
+                reflectivity: 0.1,
+                transparency: 0.8,
+                ior: 1.5,
             },
-            albedo: 0.5,
-            ks: 0.5,
-            kd: 0.05,
-        },
-    ], vec![
+        }),
+        Box::new(Plane {
+            point: Point3::new(0.0, -3.5, 0.0),
+            normal: Vector3::new(0.0, 1.0, 0.0),
+            material: Material {
+                color: Color {
+                    red: 0.6,
+                    green: 0.6,
+                    blue: 0.6,
+                },
+                albedo: 0.18,
+                ks: 0.1,
+                kd: 0.0,
+                reflectivity: 0.0,
+                transparency: 0.0,
+                ior: 1.0,
+            },
+        }),
+        Box::new(MovingSphere {
+            center0: Point3::new(-3.0, -2.0, -4.0),
+            center1: Point3::new(-1.5, -2.0, -6.0),
+            time0: 0.0,
+            time1: 1.0,
+            radius: 0.8,
+            material: Material {
+                color: Color {
+                    red: 1.0,
+                    green: 0.8,
+                    blue: 0.2,
+                },
+                albedo: 0.5,
+                ks: 0.5,
+                kd: 0.05,
+                reflectivity: 0.0,
+                transparency: 0.0,
+                ior: 1.0,
+            },
+        }),
+    ];
+
+    let camera = Camera {
+        position: Point3::new(0.0, 0.5, 2.0),
+        look_at: Point3::new(0.0, 0.0, -5.0),
+        up: Vector3::new(0.0, 1.0, 0.0),
+        fov: 90.0,
+        aperture: 0.0,
+        focus_distance: 7.0,
+        shutter_open: 0.0,
+        shutter_close: 1.0,
+    };
+
+    let scene = Scene::new(800, 800, camera, objects, vec![
         Light::DirectionalLight {
             direction: Vector3::new(-1.0, -1.0, -1.0).normalize(),
             color: Color {
@@ -333,6 +538,7 @@ fn main() {
         },
         Light::SphereLight {
             position: Point3::new(-1.2, 0.0, -4.5),
+            radius: 0.2,
             color: Color {
                 red: 1.0,
                 green: 1.0,
@@ -340,6 +546,58 @@ fn main() {
             },
             intensity: 30.0
         }
-    ], 1e-13);
-    render(&scene).save("test.png").unwrap();
+    ], 1e-13, 5, 16, 0, 8, RenderMode::Whitted);
+    render::render(&scene).save("test.png").unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A bright, well-lit scene should still produce radiance whose channels
+    /// fall in `[0, 1]` — regression test for a past `path_trace` bug where
+    /// un-clamped radiance from a high-intensity light tripped the `assert!`
+    /// in `Color::to_rgba`.
+    #[test]
+    fn path_trace_radiance_is_clamped() {
+        let objects: Vec<Box<dyn Intersectable>> = vec![Box::new(Sphere {
+            center: Point3::new(0.0, 0.0, -5.0),
+            radius: 1.0,
+            material: Material {
+                color: Color { red: 1.0, green: 1.0, blue: 1.0 },
+                albedo: 0.18,
+                ks: 0.0,
+                kd: 1.0,
+                reflectivity: 0.0,
+                transparency: 0.0,
+                ior: 1.0,
+            },
+        })];
+
+        let lights = vec![Light::SphereLight {
+            position: Point3::new(0.0, 3.0, -5.0),
+            radius: 0.5,
+            color: Color { red: 1.0, green: 1.0, blue: 1.0 },
+            intensity: 30.0,
+        }];
+
+        let camera = Camera {
+            position: Point3::new(0.0, 0.0, 0.0),
+            look_at: Point3::new(0.0, 0.0, -5.0),
+            up: Vector3::new(0.0, 1.0, 0.0),
+            fov: 90.0,
+            aperture: 0.0,
+            focus_distance: 5.0,
+            shutter_open: 0.0,
+            shutter_close: 0.0,
+        };
+
+        let scene = Scene::new(1, 1, camera, objects, lights, 1e-13, 5, 16, 0, 1, RenderMode::PathTracing);
+        let ray = scene.camera.create_ray(0, 0, 1, 1, 0.5, 0.5);
+        let radiance = pathtrace::path_trace(&scene, &ray, scene.max_depth);
+
+        assert!(radiance.red >= 0.0 && radiance.red <= 1.0);
+        assert!(radiance.green >= 0.0 && radiance.green <= 1.0);
+        assert!(radiance.blue >= 0.0 && radiance.blue <= 1.0);
+    }
 }
@@ -0,0 +1,17 @@
+use crate::color::Color;
+
+/// Surface properties shared by every `Intersectable`, so that lighting and
+/// reflection/refraction code can stay generic over the concrete primitive.
+#[derive(Clone, Debug)]
+pub struct Material {
+    pub color: Color,
+    pub albedo: f32,
+    pub ks: f32,
+    pub kd: f32,
+    /// Fraction of incoming light reflected specularly, in `[0, 1]`.
+    pub reflectivity: f32,
+    /// Fraction of incoming light transmitted through the surface, in `[0, 1]`.
+    pub transparency: f32,
+    /// Index of refraction, used when `transparency > 0.0`.
+    pub ior: f32,
+}
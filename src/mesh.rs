@@ -0,0 +1,81 @@
+use cgmath::prelude::*;
+use cgmath::{Point3, Vector3};
+
+use crate::aabb::Aabb;
+use crate::geometry::Intersectable;
+use crate::material::Material;
+use crate::Ray;
+
+const EPSILON: f64 = 1e-8;
+
+/// A single triangle, as produced by the `.obj` loader. A mesh is simply a
+/// `Vec<Triangle>` dropped into `Scene::objects` alongside other primitives.
+#[derive(Clone, Debug)]
+pub struct Triangle {
+    pub v0: Point3<f64>,
+    pub v1: Point3<f64>,
+    pub v2: Point3<f64>,
+    pub material: Material,
+}
+
+impl Intersectable for Triangle {
+    fn intersect(&self, ray: &Ray) -> Option<f64> {
+        // Moller-Trumbore ray/triangle intersection.
+        let edge1 = self.v1 - self.v0;
+        let edge2 = self.v2 - self.v0;
+
+        let p_vec = ray.direction.cross(edge2);
+        let det = edge1.dot(p_vec);
+
+        if det.abs() < EPSILON {
+            return None;
+        }
+
+        let inv_det = 1.0 / det;
+        let t_vec = ray.origin - self.v0;
+
+        let u = t_vec.dot(p_vec) * inv_det;
+        if u < 0.0 || u > 1.0 {
+            return None;
+        }
+
+        let q_vec = t_vec.cross(edge1);
+        let v = ray.direction.dot(q_vec) * inv_det;
+        if v < 0.0 || u + v > 1.0 {
+            return None;
+        }
+
+        let t = edge2.dot(q_vec) * inv_det;
+
+        if t < EPSILON {
+            return None;
+        }
+
+        Some(t)
+    }
+
+    fn surface_normal(&self, _hit_point: &Point3<f64>, _time: f64) -> Vector3<f64> {
+        let edge1 = self.v1 - self.v0;
+        let edge2 = self.v2 - self.v0;
+        edge1.cross(edge2).normalize()
+    }
+
+    fn material(&self) -> &Material {
+        &self.material
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        let min = Point3::new(
+            self.v0.x.min(self.v1.x).min(self.v2.x),
+            self.v0.y.min(self.v1.y).min(self.v2.y),
+            self.v0.z.min(self.v1.z).min(self.v2.z),
+        );
+        let max = Point3::new(
+            self.v0.x.max(self.v1.x).max(self.v2.x),
+            self.v0.y.max(self.v1.y).max(self.v2.y),
+            self.v0.z.max(self.v1.z).max(self.v2.z),
+        );
+
+        Some(Aabb { min, max })
+    }
+}
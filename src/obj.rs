@@ -0,0 +1,67 @@
+use std::fs;
+use std::path::Path;
+
+use cgmath::Point3;
+
+use crate::material::Material;
+use crate::mesh::Triangle;
+
+/// Loads the vertex/face data of a Wavefront `.obj` file into a flat list of
+/// triangles, all sharing `material`. Only `v` and `f` lines are understood;
+/// faces are triangulated as a fan if more than 3 vertices are given, and the
+/// `vt`/`vn` indices in `f` records (e.g. `f 1/1/1 2/2/1 3/3/1`) are ignored.
+pub fn load_obj<P: AsRef<Path>>(path: P, material: Material) -> Result<Vec<Triangle>, String> {
+    let contents = fs::read_to_string(path).map_err(|e| e.to_string())?;
+
+    let mut vertices: Vec<Point3<f64>> = Vec::new();
+    let mut triangles: Vec<Triangle> = Vec::new();
+
+    for line in contents.lines() {
+        let mut tokens = line.split_whitespace();
+
+        match tokens.next() {
+            Some("v") => {
+                let coords: Vec<f64> = tokens
+                    .take(3)
+                    .map(|c| c.parse().map_err(|_| format!("invalid vertex component: {}", c)))
+                    .collect::<Result<_, _>>()?;
+
+                if coords.len() != 3 {
+                    return Err(format!("malformed vertex line: {}", line));
+                }
+
+                vertices.push(Point3::new(coords[0], coords[1], coords[2]));
+            }
+            Some("f") => {
+                // A face index may be "v", "v/vt" or "v/vt/vn"; we only need `v`.
+                let indices: Vec<usize> = tokens
+                    .map(|t| {
+                        t.split('/')
+                            .next()
+                            .unwrap()
+                            .parse::<usize>()
+                            .map_err(|_| format!("invalid face index: {}", t))
+                            .map(|i| i - 1)
+                    })
+                    .collect::<Result<_, _>>()?;
+
+                if indices.len() < 3 {
+                    return Err(format!("malformed face line: {}", line));
+                }
+
+                // Triangulate the face as a fan around its first vertex.
+                for i in 1..indices.len() - 1 {
+                    triangles.push(Triangle {
+                        v0: vertices[indices[0]],
+                        v1: vertices[indices[i]],
+                        v2: vertices[indices[i + 1]],
+                        material: material.clone(),
+                    });
+                }
+            }
+            _ => continue,
+        }
+    }
+
+    Ok(triangles)
+}
@@ -0,0 +1,105 @@
+use cgmath::prelude::*;
+use cgmath::Vector3;
+use rand::Rng;
+
+use crate::color::Color;
+use crate::{Ray, Scene};
+
+/// Builds an orthonormal tangent frame `(tangent, bitangent)` around `normal`.
+fn tangent_frame(normal: Vector3<f64>) -> (Vector3<f64>, Vector3<f64>) {
+    let up = if normal.x.abs() > 0.9 { Vector3::new(0.0, 1.0, 0.0) } else { Vector3::new(1.0, 0.0, 0.0) };
+    let tangent = up.cross(normal).normalize();
+    let bitangent = normal.cross(tangent);
+
+    (tangent, bitangent)
+}
+
+/// Cosine-weighted sample of the hemisphere around `normal`.
+fn sample_hemisphere_cosine(normal: Vector3<f64>, rng: &mut impl Rng) -> Vector3<f64> {
+    let r1: f64 = rng.gen();
+    let r2: f64 = rng.gen();
+
+    let r = r1.sqrt();
+    let phi = 2.0 * std::f64::consts::PI * r2;
+
+    let (tangent, bitangent) = tangent_frame(normal);
+    tangent * (r * phi.cos()) + bitangent * (r * phi.sin()) + normal * (1.0 - r1).sqrt()
+}
+
+/// Estimates the color seen along `ray` by path tracing: at each bounce, one
+/// light is sampled for next-event-estimation direct lighting, and the path
+/// continues by cosine-weighted hemisphere sampling. Paths are terminated
+/// either after `max_depth` bounces or, past a few bounces, by Russian
+/// roulette with survival probability proportional to the path throughput.
+pub fn path_trace(scene: &Scene, ray: &Ray, max_depth: u32) -> Color {
+    let mut rng = rand::thread_rng();
+
+    let mut radiance = Color::default();
+    let mut throughput = Color { red: 1.0, green: 1.0, blue: 1.0 };
+    let mut current_ray = Ray { origin: ray.origin, direction: ray.direction, time: ray.time };
+
+    const ROULETTE_START_BOUNCE: u32 = 3;
+
+    for bounce in 0..max_depth {
+        let coll = match scene.trace(&current_ray) {
+            Some(coll) => coll,
+            None => break,
+        };
+
+        let hit_point = current_ray.origin + current_ray.direction * coll.distance;
+        let normal = coll.object.surface_normal(&hit_point, current_ray.time);
+        let material = coll.object.material();
+
+        if !scene.lights.is_empty() {
+            let light = &scene.lights[rng.gen_range(0, scene.lights.len())];
+            let (direction_to_light, distance, pdf) = light.sample_ray(&hit_point);
+            let cos_theta = normal.dot(direction_to_light).max(0.0);
+
+            // Guard against NaNs: a zero pdf or grazing sample contributes nothing.
+            if pdf > 0.0 && cos_theta > 0.0 {
+                let shadow_ray = Ray {
+                    origin: hit_point + (normal * scene.shadow_bias),
+                    direction: direction_to_light,
+                    time: current_ray.time,
+                };
+                let occluded = scene
+                    .trace(&shadow_ray)
+                    .map_or(false, |s| s.distance < distance - scene.shadow_bias);
+
+                if !occluded {
+                    let brdf = material.albedo / std::f32::consts::PI;
+                    let weight = (cos_theta as f32 / pdf as f32) * scene.lights.len() as f32;
+
+                    radiance = radiance
+                        + throughput.clone() * material.color.clone() * light.color().clone()
+                        * light.radiance() * brdf * weight;
+                }
+            }
+        }
+
+        if bounce >= ROULETTE_START_BOUNCE {
+            let survival = throughput.max_component().min(0.95);
+
+            if rng.gen::<f32>() > survival {
+                break;
+            }
+
+            throughput = throughput * (1.0 / survival);
+        }
+
+        // The cosine-weighted sampling pdf (cosθ/π) cancels the Lambertian
+        // brdf (albedo/π) and the cosθ term exactly, so the new throughput
+        // is just scaled by the surface's albedo color.
+        throughput = throughput * material.color.clone() * material.albedo;
+
+        let bounce_direction = sample_hemisphere_cosine(normal, &mut rng);
+        current_ray = Ray {
+            origin: hit_point + (normal * scene.shadow_bias),
+            direction: bounce_direction,
+            time: current_ray.time,
+        };
+    }
+
+    radiance.clamp();
+    radiance
+}
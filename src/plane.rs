@@ -0,0 +1,46 @@
+use cgmath::prelude::*;
+use cgmath::{Point3, Vector3};
+
+use crate::aabb::Aabb;
+use crate::geometry::Intersectable;
+use crate::material::Material;
+use crate::Ray;
+
+#[derive(Debug)]
+pub struct Plane {
+    pub point: Point3<f64>,
+    pub normal: Vector3<f64>,
+    pub material: Material,
+}
+
+impl Intersectable for Plane {
+    fn intersect(&self, ray: &Ray) -> Option<f64> {
+        let denom = self.normal.dot(ray.direction);
+
+        if denom.abs() < 1e-6 {
+            return None;
+        }
+
+        let t = (self.point - ray.origin).dot(self.normal) / denom;
+
+        if t < 0.0 {
+            return None;
+        }
+
+        Some(t)
+    }
+
+    fn surface_normal(&self, _hit_point: &Point3<f64>, _time: f64) -> Vector3<f64> {
+        self.normal
+    }
+
+    fn material(&self) -> &Material {
+        &self.material
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        // An infinite plane has no finite bounding box; it is tested directly
+        // rather than through the BVH.
+        None
+    }
+}
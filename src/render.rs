@@ -0,0 +1,130 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use image::{DynamicImage, GenericImage};
+use rand::Rng;
+use rayon::prelude::*;
+use rayon::ThreadPoolBuilder;
+
+use crate::color::Color;
+use crate::pathtrace::path_trace;
+use crate::{cast_ray, RenderMode, Scene};
+
+/// A rectangular block of pixels, in `[x0, x1) x [y0, y1)`, rendered as a unit
+/// so that work can be handed out to the thread pool tile-by-tile.
+#[derive(Clone, Copy)]
+struct Tile {
+    x0: u32,
+    y0: u32,
+    x1: u32,
+    y1: u32,
+}
+
+impl Tile {
+    fn width(&self) -> u32 {
+        self.x1 - self.x0
+    }
+
+    fn height(&self) -> u32 {
+        self.y1 - self.y0
+    }
+}
+
+fn make_tiles(width: u32, height: u32, tile_size: u32) -> Vec<Tile> {
+    let mut tiles = Vec::new();
+
+    let mut y0 = 0;
+    while y0 < height {
+        let y1 = (y0 + tile_size).min(height);
+
+        let mut x0 = 0;
+        while x0 < width {
+            let x1 = (x0 + tile_size).min(width);
+
+            tiles.push(Tile { x0, y0, x1, y1 });
+            x0 = x1;
+        }
+
+        y0 = y1;
+    }
+
+    tiles
+}
+
+fn trace_pixel(scene: &Scene, x: u32, y: u32, offset_x: f64, offset_y: f64) -> Color {
+    let ray = scene.camera.create_ray(x, y, scene.width, scene.height, offset_x, offset_y);
+
+    match scene.render_mode {
+        RenderMode::Whitted => cast_ray(scene, &ray, scene.max_depth),
+        RenderMode::PathTracing => path_trace(scene, &ray, scene.max_depth),
+    }
+}
+
+fn sample_pixel(scene: &Scene, x: u32, y: u32) -> Color {
+    if scene.samples_per_pixel <= 1 {
+        return trace_pixel(scene, x, y, 0.5, 0.5);
+    }
+
+    let mut rng = rand::thread_rng();
+    let mut color = Color::default();
+
+    for _ in 0..scene.samples_per_pixel {
+        color = color + trace_pixel(scene, x, y, rng.gen(), rng.gen());
+    }
+
+    color * (1.0 / scene.samples_per_pixel as f32)
+}
+
+fn render_tile(scene: &Scene, tile: &Tile) -> Vec<Color> {
+    let mut buffer = Vec::with_capacity((tile.width() * tile.height()) as usize);
+
+    for y in tile.y0..tile.y1 {
+        for x in tile.x0..tile.x1 {
+            buffer.push(sample_pixel(scene, x, y));
+        }
+    }
+
+    buffer
+}
+
+/// Renders `scene` by splitting the image into `scene.tile_size`x`scene.tile_size`
+/// tiles and dispatching them across a pool of `scene.num_threads` workers (0
+/// meaning "let rayon pick"), each writing into its own tile buffer. `Scene` is
+/// read-only during tracing, so tiles are handed out by shared reference.
+/// Completion percentage is reported to stderr as tiles finish.
+pub fn render(scene: &Scene) -> DynamicImage {
+    let mut image = DynamicImage::new_rgb8(scene.width, scene.height);
+
+    let tiles = make_tiles(scene.width, scene.height, scene.tile_size);
+    let total_tiles = tiles.len();
+    let completed = AtomicUsize::new(0);
+
+    let pool = ThreadPoolBuilder::new()
+        .num_threads(scene.num_threads)
+        .build()
+        .unwrap();
+
+    let rendered: Vec<(Tile, Vec<Color>)> = pool.install(|| {
+        tiles
+            .par_iter()
+            .map(|tile| {
+                let buffer = render_tile(scene, tile);
+
+                let done = completed.fetch_add(1, Ordering::SeqCst) + 1;
+                eprint!("\rRendering: {:>3}%", done * 100 / total_tiles);
+
+                (*tile, buffer)
+            })
+            .collect()
+    });
+    eprintln!();
+
+    for (tile, buffer) in rendered {
+        for (i, color) in buffer.into_iter().enumerate() {
+            let x = tile.x0 + (i as u32 % tile.width());
+            let y = tile.y0 + (i as u32 / tile.width());
+            image.put_pixel(x, y, color.to_rgba());
+        }
+    }
+
+    image
+}
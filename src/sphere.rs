@@ -0,0 +1,127 @@
+use cgmath::prelude::*;
+use cgmath::{Point3, Vector3};
+
+use crate::aabb::Aabb;
+use crate::geometry::Intersectable;
+use crate::material::Material;
+use crate::Ray;
+
+#[derive(Debug)]
+pub struct Sphere {
+    pub center: Point3<f64>,
+    pub radius: f64,
+    pub material: Material,
+}
+
+impl Intersectable for Sphere {
+    fn intersect(&self, ray: &Ray) -> Option<f64> {
+        // Vector from the sphere center to ray
+        let hypo: Vector3<f64> = self.center - ray.origin;
+
+        // Calculate the length of the adjacent side of the triangle
+        let adj = hypo.dot(ray.direction);
+
+        // Calculate the orthogonal distance from sphere origin to ray
+        let d = hypo.dot(hypo) - (adj * adj);
+
+        let radius_sq = self.radius * self.radius;
+
+        if d > radius_sq {
+            return None;
+        }
+
+        let thickness = (radius_sq - d).sqrt();
+
+        let t0 = adj - thickness;
+        let t1 = adj + thickness;
+
+        if t0 < 0.0 && t1 < 0.0 {
+            return None;
+        }
+
+        Some(if t0 < t1 { t0 } else { t1 })
+    }
+
+    fn surface_normal(&self, hit_point: &Point3<f64>, _time: f64) -> Vector3<f64> {
+        (hit_point - self.center).normalize()
+    }
+
+    fn material(&self) -> &Material {
+        &self.material
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        let r = Vector3::new(self.radius, self.radius, self.radius);
+        Some(Aabb {
+            min: self.center - r,
+            max: self.center + r,
+        })
+    }
+}
+
+/// A sphere whose center moves linearly between `center0` (at `time0`) and
+/// `center1` (at `time1`). A static `Sphere` is just the zero-motion case of
+/// this, so it is kept as its own type for the common case.
+#[derive(Debug)]
+pub struct MovingSphere {
+    pub center0: Point3<f64>,
+    pub center1: Point3<f64>,
+    pub time0: f64,
+    pub time1: f64,
+    pub radius: f64,
+    pub material: Material,
+}
+
+impl MovingSphere {
+    fn center(&self, time: f64) -> Point3<f64> {
+        let t = ((time - self.time0) / (self.time1 - self.time0)).max(0.0).min(1.0);
+        self.center0 + (self.center1 - self.center0) * t
+    }
+
+    /// The bounding box over the whole shutter interval, used for the BVH.
+    fn bounding_box_over_time(&self) -> Aabb {
+        let r = Vector3::new(self.radius, self.radius, self.radius);
+        let box0 = Aabb { min: self.center0 - r, max: self.center0 + r };
+        let box1 = Aabb { min: self.center1 - r, max: self.center1 + r };
+        box0.union(&box1)
+    }
+}
+
+impl Intersectable for MovingSphere {
+    fn intersect(&self, ray: &Ray) -> Option<f64> {
+        let center = self.center(ray.time);
+        let hypo: Vector3<f64> = center - ray.origin;
+
+        let adj = hypo.dot(ray.direction);
+        let d = hypo.dot(hypo) - (adj * adj);
+
+        let radius_sq = self.radius * self.radius;
+
+        if d > radius_sq {
+            return None;
+        }
+
+        let thickness = (radius_sq - d).sqrt();
+
+        let t0 = adj - thickness;
+        let t1 = adj + thickness;
+
+        if t0 < 0.0 && t1 < 0.0 {
+            return None;
+        }
+
+        Some(if t0 < t1 { t0 } else { t1 })
+    }
+
+    fn surface_normal(&self, hit_point: &Point3<f64>, time: f64) -> Vector3<f64> {
+        (hit_point - self.center(time)).normalize()
+    }
+
+    fn material(&self) -> &Material {
+        &self.material
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        Some(self.bounding_box_over_time())
+    }
+}